@@ -2,6 +2,11 @@ use std::io::BufRead;
 use std::collections::{HashMap, HashSet};
 use super::puzzle_state::PuzzleState;
 
+mod simd;
+mod union_find;
+
+use union_find::UnionFind;
+
 quick_error! {
     #[derive(Debug)]
     pub enum GeometryError {
@@ -9,6 +14,7 @@ quick_error! {
         InvalidVertex
         InvalidTriangle
         InvalidColor
+        InvalidSubdivisionFactor
     }
 }
 
@@ -22,6 +28,16 @@ pub struct PuzzleData {
     vertices_to_edges: HashMap<u32, HashSet<(u32, u32)>>,
     lower_bounds: (f32, f32),
     upper_bounds: (f32, f32),
+    vertex_grid: HashMap<(i32, i32), Vec<u32>>, // uniform spatial grid, keyed by cell, for get_vertex_near
+}
+
+// Cell size for the vertex spatial grid, chosen to match the typical pointer snap threshold
+// used elsewhere. `get_vertex_near` scales how many rings of cells it searches to the
+// threshold it's actually given, so a larger caller-supplied threshold still works correctly.
+const GRID_CELL_SIZE: f32 = 0.12;
+
+fn grid_cell(point: (f32, f32), cell_size: f32) -> (i32, i32) {
+    ((point.0 / cell_size).floor() as i32, (point.1 / cell_size).floor() as i32)
 }
 
 impl PuzzleData {
@@ -35,6 +51,7 @@ impl PuzzleData {
             vertices_to_edges: HashMap::new(),
             lower_bounds: (std::f32::MAX, std::f32::MAX),
             upper_bounds: (std::f32::MIN, std::f32::MIN),
+            vertex_grid: HashMap::new(),
         };
 
         // Parse geometry and colors
@@ -90,23 +107,137 @@ impl PuzzleData {
             }
         }
 
-        // Construct edge to triangle and triangle to edge membership maps
-        for (idx, triangle_data) in (&out.triangles).iter().enumerate() {
+        out.build_adjacency();
+
+        Ok(out)
+    }
+
+    // `sample_color` is called at each triangle's centroid to pick its fill color.
+    pub fn from_points(points: &[(f32, f32)], sample_color: impl Fn((f32, f32)) -> [u8; 3]) -> PuzzleData {
+        let mut out = PuzzleData {
+            vertices: points.to_vec(),
+            triangles: vec![],
+            colors: vec![],
+            edge_to_triangles: HashMap::new(),
+            triangle_to_edges: HashMap::new(),
+            vertices_to_edges: HashMap::new(),
+            lower_bounds: (std::f32::MAX, std::f32::MAX),
+            upper_bounds: (std::f32::MIN, std::f32::MIN),
+            vertex_grid: HashMap::new(),
+        };
+
+        for &(x, y) in points {
+            if x < out.lower_bounds.0 { out.lower_bounds.0 = x; }
+            if y < out.lower_bounds.1 { out.lower_bounds.1 = y; }
+            if x > out.upper_bounds.0 { out.upper_bounds.0 = x; }
+            if y > out.upper_bounds.1 { out.upper_bounds.1 = y; }
+        }
+
+        let mut color_lookup: HashMap<[u8; 3], u32> = HashMap::new();
+        for (a, b, c) in bowyer_watson_triangulate(points, out.lower_bounds, out.upper_bounds) {
+            let centroid = (
+                (points[a].0 + points[b].0 + points[c].0) / 3.0,
+                (points[a].1 + points[b].1 + points[c].1) / 3.0,
+            );
+            let rgb = sample_color(centroid);
+            let colors = &mut out.colors;
+            let color_idx = *color_lookup.entry(rgb).or_insert_with(|| {
+                colors.push([rgb[0] as f32 / 255.0, rgb[1] as f32 / 255.0, rgb[2] as f32 / 255.0]);
+                (colors.len() - 1) as u32
+            });
+            out.triangles.push([a as u32, b as u32, c as u32, color_idx]);
+        }
+
+        out.build_adjacency();
+        out
+    }
+
+    // Shared by every constructor so the maps always agree with the triangle list.
+    fn build_adjacency(&mut self) {
+        self.edge_to_triangles.clear();
+        self.triangle_to_edges.clear();
+        self.vertices_to_edges.clear();
+
+        for (idx, triangle_data) in (&self.triangles).iter().enumerate() {
             let mut sorted = triangle_data[0..3].to_vec();
             sorted.sort();
             let triangle_to_edges = [(sorted[0], sorted[1]), (sorted[1], sorted[2]), (sorted[0], sorted[2])];
             for (e0, e1) in &triangle_to_edges {
-                out.edge_to_triangles.entry((*e0, *e1)).or_insert(vec![]).push(idx);
+                self.edge_to_triangles.entry((*e0, *e1)).or_insert(vec![]).push(idx);
             }
-            out.triangle_to_edges.insert(idx as u32, triangle_to_edges);
+            self.triangle_to_edges.insert(idx as u32, triangle_to_edges);
+        }
+
+        for edge in self.edge_to_triangles.keys() {
+            self.vertices_to_edges.entry(edge.0).or_insert(HashSet::new()).insert(*edge);
+            self.vertices_to_edges.entry(edge.1).or_insert(HashSet::new()).insert(*edge);
+        }
+
+        self.build_vertex_grid();
+    }
+
+    // Buckets vertex indices into a uniform grid so get_vertex_near only has to test the
+    // handful of vertices near a query point instead of scanning the whole vertex list.
+    fn build_vertex_grid(&mut self) {
+        self.vertex_grid.clear();
+        for (idx, &vertex) in (&self.vertices).iter().enumerate() {
+            self.vertex_grid.entry(grid_cell(vertex, GRID_CELL_SIZE)).or_insert(vec![]).push(idx as u32);
         }
+    }
 
-        // Construct vertex to edge map
-        for edge in out.edge_to_triangles.keys() {
-            out.vertices_to_edges.entry(edge.0).or_insert(HashSet::new()).insert(*edge);
-            out.vertices_to_edges.entry(edge.1).or_insert(HashSet::new()).insert(*edge);
+    // Splits every triangle into n^2 smaller triangles over a barycentric lattice. Lattice
+    // points on a shared edge or corner are deduped across neighboring triangles via
+    // `subdivision_vertex_key` so the adjacency maps stay consistent.
+    pub fn subdivide(self, n: u32) -> Result<PuzzleData, GeometryError> {
+        if n < 1 { return Err(GeometryError::InvalidSubdivisionFactor) }
+
+        let mut vertices: Vec<(f32, f32)> = vec![];
+        let mut vertex_keys: HashMap<SubdivisionVertexKey, u32> = HashMap::new();
+        let mut triangles: Vec<[u32; 4]> = vec![];
+
+        for (t_idx, triangle) in self.triangles.iter().enumerate() {
+            let (a, b, c, color_idx) = (triangle[0], triangle[1], triangle[2], triangle[3]);
+            let (pa, pb, pc) = (self.vertices[a as usize], self.vertices[b as usize], self.vertices[c as usize]);
+
+            let mut lattice_vertex = |j: u32, k: u32| -> u32 {
+                let i = n - j - k;
+                let key = subdivision_vertex_key(n, (a, b, c), t_idx, (i, j, k));
+                *vertex_keys.entry(key).or_insert_with(|| {
+                    let (n_f, i_f, j_f, k_f) = (n as f32, i as f32, j as f32, k as f32);
+                    vertices.push((
+                        (pa.0 * i_f + pb.0 * j_f + pc.0 * k_f) / n_f,
+                        (pa.1 * i_f + pb.1 * j_f + pc.1 * k_f) / n_f,
+                    ));
+                    (vertices.len() - 1) as u32
+                })
+            };
+
+            // Each (j, k) cell of the lattice yields an "up" triangle, and a "down" triangle
+            // wherever there's room for one, giving the usual up/down subdivision pattern.
+            for j in 0..n {
+                for k in 0..(n - j) {
+                    triangles.push([lattice_vertex(j, k), lattice_vertex(j + 1, k), lattice_vertex(j, k + 1), color_idx]);
+                    if j + k < n - 1 {
+                        triangles.push([
+                            lattice_vertex(j + 1, k), lattice_vertex(j, k + 1), lattice_vertex(j + 1, k + 1), color_idx
+                        ]);
+                    }
+                }
+            }
         }
 
+        let mut out = PuzzleData {
+            vertices,
+            triangles,
+            colors: self.colors,
+            edge_to_triangles: HashMap::new(),
+            triangle_to_edges: HashMap::new(),
+            vertices_to_edges: HashMap::new(),
+            lower_bounds: self.lower_bounds,
+            upper_bounds: self.upper_bounds,
+            vertex_grid: HashMap::new(),
+        };
+        out.build_adjacency();
         Ok(out)
     }
 
@@ -124,6 +255,18 @@ impl PuzzleData {
         self.triangle_to_edges[&triangle].to_vec()
     }
 
+    // Every edge that bounds exactly one triangle, i.e. the puzzle's silhouette/border.
+    pub fn boundary_edges(&self) -> Vec<(u32, u32)> {
+        self.edge_to_triangles.iter()
+            .filter(|(_, triangles)| triangles.len() == 1)
+            .map(|(&edge, _)| edge)
+            .collect()
+    }
+
+    pub fn is_boundary_edge(&self, edge: &(u32, u32)) -> bool {
+        self.edge_to_triangles.get(edge).map(|triangles| triangles.len() == 1).unwrap_or(false)
+    }
+
     pub fn get_static_graphics_data(&self) -> StaticGraphicsData {
         StaticGraphicsData::from_data(self)
     }
@@ -142,17 +285,93 @@ impl PuzzleData {
     }
 
     pub fn get_vertex_near(&self, state: &PuzzleState, point: (f32, f32), threshold: f32) -> Option<u32> {
-        for (idx, vertex) in (&self.vertices).iter().enumerate() {
-            if (vertex.0 - point.0).hypot(vertex.1 - point.1) <= threshold
-            && state.should_be_interactable(self, idx as u32) {
-                return Some(idx as u32)
+        let threshold_sq = simd::F32x4::splat(threshold * threshold);
+        let (px, py) = (simd::F32x4::splat(point.0), simd::F32x4::splat(point.1));
+        let (cell_x, cell_y) = grid_cell(point, GRID_CELL_SIZE);
+        // The grid only guarantees every vertex within `threshold` sits in a ring this wide
+        // around the query cell - a fixed 3x3 neighborhood is only correct when threshold fits
+        // inside one cell.
+        let cell_radius = ((threshold / GRID_CELL_SIZE).ceil() as i32).max(1);
+        // When several vertices are within `threshold`, this returns whichever the grid visits
+        // first (cell order, then bucket order), not necessarily the lowest index.
+
+        for x in (cell_x - cell_radius)..=(cell_x + cell_radius) {
+            for y in (cell_y - cell_radius)..=(cell_y + cell_radius) {
+                let bucket = match self.vertex_grid.get(&(x, y)) {
+                    Some(bucket) => bucket,
+                    None => continue,
+                };
+
+                for chunk in bucket.chunks(4) {
+                    // Pad any short final chunk with a point far from the query so its lanes
+                    // never register as a hit.
+                    let mut xs = [std::f32::MAX; 4];
+                    let mut ys = [std::f32::MAX; 4];
+                    for (i, &idx) in chunk.iter().enumerate() {
+                        let vertex = self.vertices[idx as usize];
+                        xs[i] = vertex.0;
+                        ys[i] = vertex.1;
+                    }
+
+                    let dx = simd::F32x4::from_array(&xs).sub(px);
+                    let dy = simd::F32x4::from_array(&ys).sub(py);
+                    let dist_sq = dx.mul(dx).add(dy.mul(dy));
+                    let hits = dist_sq.le_mask(threshold_sq);
+                    if hits == 0 { continue }
+
+                    for (i, &idx) in chunk.iter().enumerate() {
+                        if hits & (1 << i) != 0 && state.should_be_interactable(self, idx) {
+                            return Some(idx)
+                        }
+                    }
+                }
             }
         }
+
         None
     }
 
     pub fn get_lower_bounds(&self) -> (f32, f32) { self.lower_bounds }
     pub fn get_upper_bounds(&self) -> (f32, f32) { self.upper_bounds }
+
+    // The smallest pointer snap radius at which every vertex is reachable from every other
+    // vertex by a chain of snap connections. Found by bisecting between the closest and
+    // farthest vertex pair and, at each candidate radius, testing full connectivity with a
+    // union-find over the pairs closer than it.
+    pub fn difficulty_radius(&self) -> f32 {
+        let n = self.vertices.len();
+        if n <= 1 { return 0.0 }
+
+        let mut pair_distances = vec![];
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let ((x0, y0), (x1, y1)) = (self.vertices[i], self.vertices[j]);
+                pair_distances.push((i, j, (x0 - x1).hypot(y0 - y1)));
+            }
+        }
+
+        let (mut lo, mut hi) = pair_distances.iter()
+            .fold((std::f32::MAX, std::f32::MIN), |(lo, hi), &(_, _, d)| (lo.min(d), hi.max(d)));
+
+        // Connectivity only grows as the radius grows (the candidate edge set is monotone in
+        // `radius`), so bisecting between the closest and farthest pair converges on the
+        // smallest radius that connects the whole vertex set, regardless of whether
+        // `is_fully_connected_at_radius` happens to already be true at the starting `hi`.
+        for _ in 0..40 {
+            let mid = lo + (hi - lo) / 2.0;
+            if is_fully_connected_at_radius(n, &pair_distances, mid) { hi = mid; } else { lo = mid; }
+        }
+
+        hi
+    }
+}
+
+fn is_fully_connected_at_radius(n: usize, pair_distances: &[(usize, usize, f32)], radius: f32) -> bool {
+    let mut union_find = UnionFind::new(n);
+    for &(i, j, d) in pair_distances {
+        if d < radius { union_find.union(i, j); }
+    }
+    union_find.num_components() == 1
 }
 
 // Should only need to ever make one of these per puzzle
@@ -162,6 +381,7 @@ pub struct StaticGraphicsData {
     pub triangle_position_vertices: Vec<f32>,
     pub triangle_color_idx_vertices: Vec<f32>,
     pub colors_uniform: Vec<f32>,
+    pub outline_vertices: Vec<f32>,
 }
 
 impl StaticGraphicsData {
@@ -171,6 +391,7 @@ impl StaticGraphicsData {
             triangle_position_vertices: vec![],
             triangle_color_idx_vertices: vec![],
             colors_uniform: vec![],
+            outline_vertices: vec![],
         };
 
         for triangle in &data.triangles {
@@ -188,6 +409,13 @@ impl StaticGraphicsData {
             out.colors_uniform.append(&mut color.to_vec());
         }
 
+        // The silhouette/border of the picture, drawn once the region it bounds is solved.
+        for (v0, v1) in data.boundary_edges() {
+            let (x0, y0) = data.vertices[v0 as usize];
+            let (x1, y1) = data.vertices[v1 as usize];
+            out.outline_vertices.append(&mut vec![x0, y0, x1, y1]);
+        }
+
         out
     }
 }
@@ -321,4 +549,289 @@ impl PointQuad {
         out.textures.append(&mut vec![remaining_f, remaining_f, remaining_f, remaining_f]);
         out
     }
+}
+
+// True iff `p` lies inside the circumcircle of triangle `(a, b, c)`. Reorients the triangle
+// CCW first (via signed area) since the determinant test below only holds for that winding.
+fn in_circumcircle(a: (f32, f32), b: (f32, f32), c: (f32, f32), p: (f32, f32)) -> bool {
+    let signed_area = (b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1);
+    let (a, b, c) = if signed_area < 0.0 { (a, c, b) } else { (a, b, c) };
+
+    let row = |v: (f32, f32)| {
+        let (dx, dy) = (v.0 - p.0, v.1 - p.1);
+        (dx, dy, dx * dx + dy * dy)
+    };
+    let (r0, r1, r2) = (row(a), row(b), row(c));
+
+    let det = r0.0 * (r1.1 * r2.2 - r1.2 * r2.1)
+        - r0.1 * (r1.0 * r2.2 - r1.2 * r2.0)
+        + r0.2 * (r1.0 * r2.1 - r1.1 * r2.0);
+
+    det > 0.0
+}
+
+// Incremental Bowyer-Watson. Returns the surviving triangles as index triples into `points`
+// once every super-triangle vertex has been dropped.
+fn bowyer_watson_triangulate(
+    points: &[(f32, f32)],
+    lower_bounds: (f32, f32),
+    upper_bounds: (f32, f32),
+) -> Vec<(usize, usize, usize)> {
+    let span = (upper_bounds.0 - lower_bounds.0).max(upper_bounds.1 - lower_bounds.1).max(1.0);
+    let mid = ((lower_bounds.0 + upper_bounds.0) / 2.0, (lower_bounds.1 + upper_bounds.1) / 2.0);
+
+    // The super-triangle has to be enough bigger than the point set that a triangle pairing a
+    // super vertex with two real points never gets mistaken for part of the final Delaunay
+    // triangulation - if it's only modestly larger than the bounding box, a super vertex can end
+    // up closer to a circumcircle than a real point would be, leaving a gap where that edge
+    // should have been re-triangulated as the real points filled in around it.
+    const SUPER_TRIANGLE_MARGIN: f32 = 1000.0;
+    let super_span = span * SUPER_TRIANGLE_MARGIN;
+
+    let mut pts = points.to_vec();
+    let super_a = pts.len(); pts.push((mid.0 - 2.0 * super_span, mid.1 - super_span));
+    let super_b = pts.len(); pts.push((mid.0 + 2.0 * super_span, mid.1 - super_span));
+    let super_c = pts.len(); pts.push((mid.0, mid.1 + 2.0 * super_span));
+    let super_verts = [super_a, super_b, super_c];
+
+    let mut triangles: Vec<[usize; 3]> = vec![[super_a, super_b, super_c]];
+
+    for (p_idx, &p) in points.iter().enumerate() {
+        let is_bad = |triangles: &[[usize; 3]], t_idx: usize| {
+            let [a, b, c] = triangles[t_idx];
+            in_circumcircle(pts[a], pts[b], pts[c], p)
+        };
+
+        // A triangle's circumcircle always contains the whole triangle, so whichever triangle
+        // geometrically contains `p` is guaranteed bad - use it (or any other bad triangle found
+        // this way) as a seed and grow the cavity by walking edge-adjacent neighbors, rather than
+        // re-testing every triangle in the mesh independently. The latter can mark an unrelated
+        // triangle elsewhere as bad too, folding its edges into the same tally below and
+        // corrupting the boundary - the cavity for a single insertion is always one contiguous
+        // region, so only triangles reachable from the seed should ever be deleted.
+        let seed = (0..triangles.len()).find(|&idx| is_bad(&triangles, idx));
+        let bad_triangles: HashSet<usize> = match seed {
+            Some(seed) => {
+                let mut edge_to_triangles: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+                for (idx, &[a, b, c]) in triangles.iter().enumerate() {
+                    for &(u, v) in &[(a, b), (b, c), (c, a)] {
+                        let key = if u < v { (u, v) } else { (v, u) };
+                        edge_to_triangles.entry(key).or_insert(vec![]).push(idx);
+                    }
+                }
+
+                let mut bad = HashSet::new();
+                let mut frontier = vec![seed];
+                bad.insert(seed);
+                while let Some(t_idx) = frontier.pop() {
+                    let [a, b, c] = triangles[t_idx];
+                    for &(u, v) in &[(a, b), (b, c), (c, a)] {
+                        let key = if u < v { (u, v) } else { (v, u) };
+                        for &neighbor in &edge_to_triangles[&key] {
+                            if !bad.contains(&neighbor) && is_bad(&triangles, neighbor) {
+                                bad.insert(neighbor);
+                                frontier.push(neighbor);
+                            }
+                        }
+                    }
+                }
+                bad
+            }
+            None => HashSet::new(),
+        };
+
+        // Edges belonging to exactly one deleted ("bad") triangle bound the star-shaped hole.
+        let mut edge_counts: HashMap<(usize, usize), u32> = HashMap::new();
+        for &t_idx in &bad_triangles {
+            let [a, b, c] = triangles[t_idx];
+            for &(u, v) in &[(a, b), (b, c), (c, a)] {
+                let key = if u < v { (u, v) } else { (v, u) };
+                *edge_counts.entry(key).or_insert(0) += 1;
+            }
+        }
+        let boundary: Vec<(usize, usize)> = edge_counts.into_iter()
+            .filter(|&(_, count)| count == 1)
+            .map(|(edge, _)| edge)
+            .collect();
+
+        triangles = triangles.into_iter().enumerate()
+            .filter(|(idx, _)| !bad_triangles.contains(idx))
+            .map(|(_, t)| t)
+            .collect();
+
+        for (u, v) in boundary {
+            triangles.push([u, v, p_idx]);
+        }
+    }
+
+    triangles.into_iter()
+        .filter(|triangle| triangle.iter().all(|v| !super_verts.contains(v)))
+        .map(|[a, b, c]| (a, b, c))
+        .collect()
+}
+
+// Identifies a subdivided lattice point so neighboring triangles that share an edge or corner
+// agree on the same vertex instead of each minting their own copy.
+#[derive(PartialEq, Eq, Hash)]
+enum SubdivisionVertexKey {
+    Corner(u32),
+    Edge(u32, u32, u32), // lo vertex, hi vertex, weight on lo vertex
+    Interior(usize, u32, u32, u32), // parent triangle, i, j, k
+}
+
+fn subdivision_vertex_key(
+    n: u32, corners: (u32, u32, u32), triangle: usize, weights: (u32, u32, u32),
+) -> SubdivisionVertexKey {
+    let (a, b, c) = corners;
+    let (i, j, k) = weights;
+
+    // Canonicalize an edge point by the edge's sorted endpoints and the weight on the lower
+    // one, so both triangles that share the edge compute the same key regardless of winding.
+    let edge_key = |u: u32, v: u32, weight_on_u: u32| {
+        if u < v { SubdivisionVertexKey::Edge(u, v, weight_on_u) } else { SubdivisionVertexKey::Edge(v, u, n - weight_on_u) }
+    };
+
+    if i == n { SubdivisionVertexKey::Corner(a) }
+    else if j == n { SubdivisionVertexKey::Corner(b) }
+    else if k == n { SubdivisionVertexKey::Corner(c) }
+    else if k == 0 { edge_key(a, b, i) }
+    else if i == 0 { edge_key(b, c, j) }
+    else if j == 0 { edge_key(c, a, k) }
+    else { SubdivisionVertexKey::Interior(triangle, i, j, k) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sum of triangle areas via the public graphics data, so a gap or an overlap in the
+    // triangulation shows up as a mismatch against the known bounding area.
+    fn triangulated_area(data: &PuzzleData) -> f32 {
+        data.get_static_graphics_data().triangle_position_vertices.chunks(6).map(|t| {
+            0.5 * ((t[2] - t[0]) * (t[5] - t[1]) - (t[4] - t[0]) * (t[3] - t[1])).abs()
+        }).sum()
+    }
+
+    #[test]
+    fn from_points_triangulates_a_grid_without_gaps_or_overlaps() {
+        let points = vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)];
+        let data = PuzzleData::from_points(&points, |_| [255, 255, 255]);
+
+        assert_eq!(data.num_triangles(), 2);
+        assert!((triangulated_area(&data) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn from_points_triangulates_scattered_points_without_gaps_or_overlaps() {
+        let points = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.5, 0.5)];
+        let data = PuzzleData::from_points(&points, |_| [0, 0, 0]);
+
+        assert_eq!(data.num_triangles(), 4);
+        assert!((triangulated_area(&data) - 1.0).abs() < 1e-4);
+    }
+
+    // Gift-wrapping hull point count, used below to predict the exact triangle count a gap-free
+    // triangulation must produce (T = 2n - 2 - h).
+    fn hull_point_count(points: &[(f32, f32)]) -> usize {
+        let start = (0..points.len())
+            .min_by(|&a, &b| points[a].0.partial_cmp(&points[b].0).unwrap())
+            .unwrap();
+
+        let mut count = 0;
+        let mut current = start;
+        loop {
+            let mut next = (current + 1) % points.len();
+            for i in 0..points.len() {
+                let cross = (points[next].0 - points[current].0) * (points[i].1 - points[current].1)
+                    - (points[next].1 - points[current].1) * (points[i].0 - points[current].0);
+                if cross < 0.0 { next = i; }
+            }
+            current = next;
+            count += 1;
+            if current == start { break }
+        }
+        count
+    }
+
+    // A tiny xorshift PRNG so this test doesn't need a dependency just to fuzz point sets.
+    struct Xorshift(u64);
+    impl Xorshift {
+        fn next_unit(&mut self) -> f32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 >> 40) as f32 / (1u64 << 24) as f32
+        }
+    }
+
+    // The reviewer's original repro for the triangulator silently dropping a triangle: a point
+    // set where the naive "scan every triangle independently" cavity search corrupts the
+    // boundary-edge tally instead of growing one contiguous hole around the inserted point.
+    #[test]
+    fn from_points_matches_topological_invariant_on_a_known_gap_repro() {
+        let points = vec![
+            (0.874383, 0.435005),
+            (0.997283, 0.281538),
+            (0.452034, 0.590757),
+            (0.106729, 0.028976),
+            (0.109918, 0.926692),
+        ];
+        let h = hull_point_count(&points);
+        let data = PuzzleData::from_points(&points, |_| [0, 0, 0]);
+
+        assert_eq!(data.num_triangles(), 2 * points.len() - 2 - h);
+    }
+
+    // A gap or an overlap shows up as a triangle count that doesn't match the topological
+    // invariant T = 2n - 2 - h, which catches non-contiguous-cavity bugs that small fixed point
+    // sets can dodge.
+    #[test]
+    fn from_points_matches_topological_invariant_over_random_point_sets() {
+        let mut rng = Xorshift(88172645463325252);
+
+        for _ in 0..500 {
+            let n = 4 + (rng.next_unit() * 20.0) as usize;
+            let mut points = vec![];
+            while points.len() < n {
+                let p = (rng.next_unit(), rng.next_unit());
+                if !points.contains(&p) { points.push(p); }
+            }
+
+            let h = hull_point_count(&points);
+            let data = PuzzleData::from_points(&points, |_| [0, 0, 0]);
+            assert_eq!(data.num_triangles(), 2 * points.len() - 2 - h, "points: {:?}", points);
+        }
+    }
+
+    #[test]
+    fn subdivide_rejects_a_zero_factor() {
+        let data = PuzzleData::from_points(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)], |_| [0, 0, 0]);
+        assert!(matches!(data.subdivide(0), Err(GeometryError::InvalidSubdivisionFactor)));
+    }
+
+    #[test]
+    fn subdivide_dedupes_the_shared_edge_of_two_triangles() {
+        let points = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let data = PuzzleData::from_points(&points, |_| [0, 0, 0]);
+        assert_eq!(data.num_triangles(), 2);
+
+        let subdivided = data.subdivide(2).unwrap();
+
+        // Each triangle alone would lattice into 6 vertices; the shared edge's 3 lattice
+        // points (2 corners + 1 midpoint) must be shared rather than duplicated, leaving 9.
+        assert_eq!(subdivided.get_static_graphics_data().num_vertices, 9);
+        assert_eq!(subdivided.num_triangles(), 8);
+        assert!((triangulated_area(&subdivided) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn difficulty_radius_of_a_unit_square_is_the_side_length() {
+        let points = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let data = PuzzleData::from_points(&points, |_| [0, 0, 0]);
+
+        // The 4 side pairs (distance 1) already form a cycle through every vertex, so a
+        // radius just above the side length connects the whole set without the diagonals
+        // (distance sqrt(2)) ever being needed.
+        assert!((data.difficulty_radius() - 1.0).abs() < 1e-3);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+// Small four-lane f32 wrapper used to batch the squared-distance check in
+// `PuzzleData::get_vertex_near` so a bucket of nearby vertices can be tested against the
+// snap threshold without a per-vertex `sqrt`.
+
+#[cfg(target_arch = "x86_64")]
+pub use x86::F32x4;
+
+#[cfg(not(target_arch = "x86_64"))]
+pub use scalar::F32x4;
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use std::arch::x86_64::*;
+
+    #[derive(Clone, Copy)]
+    pub struct F32x4(__m128);
+
+    impl F32x4 {
+        #[inline]
+        pub fn splat(v: f32) -> F32x4 { unsafe { F32x4(_mm_set1_ps(v)) } }
+
+        #[inline]
+        pub fn from_array(v: &[f32; 4]) -> F32x4 { unsafe { F32x4(_mm_loadu_ps(v.as_ptr())) } }
+
+        #[inline]
+        pub fn sub(self, other: F32x4) -> F32x4 { unsafe { F32x4(_mm_sub_ps(self.0, other.0)) } }
+
+        #[inline]
+        pub fn mul(self, other: F32x4) -> F32x4 { unsafe { F32x4(_mm_mul_ps(self.0, other.0)) } }
+
+        #[inline]
+        pub fn add(self, other: F32x4) -> F32x4 { unsafe { F32x4(_mm_add_ps(self.0, other.0)) } }
+
+        // Bit `i` of the result is set when lane `i` of `self` is <= lane `i` of `other`.
+        #[inline]
+        pub fn le_mask(self, other: F32x4) -> u32 {
+            unsafe { _mm_movemask_ps(_mm_cmple_ps(self.0, other.0)) as u32 }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+mod scalar {
+    #[derive(Clone, Copy)]
+    pub struct F32x4([f32; 4]);
+
+    impl F32x4 {
+        pub fn splat(v: f32) -> F32x4 { F32x4([v; 4]) }
+        pub fn from_array(v: &[f32; 4]) -> F32x4 { F32x4(*v) }
+
+        pub fn sub(self, other: F32x4) -> F32x4 {
+            F32x4([self.0[0] - other.0[0], self.0[1] - other.0[1], self.0[2] - other.0[2], self.0[3] - other.0[3]])
+        }
+
+        pub fn mul(self, other: F32x4) -> F32x4 {
+            F32x4([self.0[0] * other.0[0], self.0[1] * other.0[1], self.0[2] * other.0[2], self.0[3] * other.0[3]])
+        }
+
+        pub fn add(self, other: F32x4) -> F32x4 {
+            F32x4([self.0[0] + other.0[0], self.0[1] + other.0[1], self.0[2] + other.0[2], self.0[3] + other.0[3]])
+        }
+
+        pub fn le_mask(self, other: F32x4) -> u32 {
+            (0..4).fold(0, |mask, i| if self.0[i] <= other.0[i] { mask | (1 << i) } else { mask })
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// Disjoint-set structure used by `PuzzleData::difficulty_radius` to test connectivity of the
+// vertex set at a candidate snap radius. A fresh one is built for each radius tested.
+
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    components: usize,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind { parent: (0..n).collect(), rank: vec![0; n], components: n }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b { return }
+
+        match self.rank[root_a].cmp(&self.rank[root_b]) {
+            std::cmp::Ordering::Less => self.parent[root_a] = root_b,
+            std::cmp::Ordering::Greater => self.parent[root_b] = root_a,
+            std::cmp::Ordering::Equal => { self.parent[root_b] = root_a; self.rank[root_a] += 1; },
+        }
+        self.components -= 1;
+    }
+
+    pub fn num_components(&self) -> usize { self.components }
+}
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use super::geometry;
 
+#[derive(Clone)]
 pub struct PuzzleState {
     triangle_reqs: Vec<u32>,
     unlocked_triangles: HashSet<usize>,
@@ -98,4 +99,83 @@ impl PuzzleState {
         let has_non_permanent = self.get_non_permanent_edges_for_vertex(vertex) > 0;
         not_done || has_non_permanent
     }
+
+    // The most valuable next edge to connect: prefer one that belongs to a triangle one edge
+    // away from unlocking, breaking ties by how many still-locked triangles share it.
+    pub fn hint(&self, data: &geometry::PuzzleData) -> Option<(u32, u32)> {
+        let mut best: Option<((u32, u32), bool, usize)> = None;
+
+        for triangle in 0..self.triangle_reqs.len() {
+            if self.unlocked_triangles.contains(&triangle) { continue }
+            let unlocks_triangle = self.triangle_reqs[triangle] == 1;
+
+            for edge in data.get_edges_for_triangle(triangle as u32) {
+                if self.connected_edges.contains(&edge) { continue }
+
+                let locked_triangles_sharing = data.triangles_with_edge(&edge)
+                    .map(|triangles| triangles.iter().filter(|&&t| !self.unlocked_triangles.contains(&t)).count())
+                    .unwrap_or(0);
+
+                let is_better = match best {
+                    None => true,
+                    Some((_, best_unlocks, best_locked)) => (unlocks_triangle, locked_triangles_sharing) > (best_unlocks, best_locked),
+                };
+                if is_better { best = Some((edge, unlocks_triangle, locked_triangles_sharing)); }
+            }
+        }
+
+        best.map(|(edge, _, _)| edge)
+    }
+
+    // An ordered edge sequence that finishes the puzzle, by repeatedly taking the hint against
+    // a scratch copy of this state. Lets the UI auto-complete or step through a solution.
+    pub fn solve(&self, data: &geometry::PuzzleData) -> Vec<(u32, u32)> {
+        let mut sequence = vec![];
+        let mut working = self.clone();
+
+        while !working.is_finished() {
+            match working.hint(data) {
+                Some(edge) => {
+                    working.connect_edge(data, &edge);
+                    sequence.push(edge);
+                },
+                None => break,
+            }
+        }
+
+        sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_square() -> geometry::PuzzleData {
+        let points = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        geometry::PuzzleData::from_points(&points, |_| [0, 0, 0])
+    }
+
+    #[test]
+    fn hint_prefers_an_edge_shared_by_two_locked_triangles() {
+        let data = unit_square();
+        let state = PuzzleState::from_data(&data);
+
+        let hint = state.hint(&data).unwrap();
+        assert_eq!(data.triangles_with_edge(&hint).map(|t| t.len()), Some(2));
+    }
+
+    #[test]
+    fn solve_connects_every_hinted_edge_and_finishes_the_puzzle() {
+        let data = unit_square();
+        let state = PuzzleState::from_data(&data);
+
+        let sequence = state.solve(&data);
+
+        let mut finished = state.clone();
+        for edge in &sequence {
+            finished.connect_edge(&data, edge);
+        }
+        assert!(finished.is_finished());
+    }
 }
\ No newline at end of file